@@ -0,0 +1,25 @@
+use deadpool_postgres::{Client, Pool, PoolError};
+
+/// Generated companion to `ServerState` — exposes the connection pool backing
+/// this service so handlers can pull a pooled client straight out of
+/// `web::Data<S>` instead of threading one through by hand.
+pub trait DbPool {
+    fn pool(&self) -> &Pool;
+}
+
+/// Checks out a pooled client from `state`, mapping pool exhaustion into `{{ error_type }}::DbError`.
+pub async fn get_client<S: DbPool>(state: &actix_web::web::Data<S>) -> Result<Client, {{ error_type }}> {
+    state.pool().get().await.map_err({{ error_type }}::from)
+}
+
+impl From<PoolError> for {{ error_type }} {
+    fn from(err: PoolError) -> Self {
+        {{ error_type }}::DbError(err.to_string())
+    }
+}
+
+impl From<tokio_postgres::Error> for {{ error_type }} {
+    fn from(err: tokio_postgres::Error) -> Self {
+        {{ error_type }}::DbError(err.to_string())
+    }
+}
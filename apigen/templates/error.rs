@@ -4,6 +4,9 @@ pub enum {{ error_type }} {
     {% for variant in variants %}
     {{ variant.detail | to_camel_case }},
     {% endfor %}
+    {% if db %}
+    DbError(String),
+    {% endif %}
 }
 
 impl Display for {{ error_type }} {
@@ -14,18 +17,68 @@ impl Display for {{ error_type }} {
                 write!(f, "{{variant.detail}}")
             },
             {% endfor %}
+            {% if db %}
+            {{error_type}}::DbError(cause) => {
+                write!(f, "database error: {}", cause)
+            },
+            {% endif %}
         }
     }
 }
 
 impl std::error::Error for {{error_type}} {}
 
+/// Machine-parseable JSON body rendered for every `{{ error_type }}` response.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct {{ error_type }}Body {
+    pub error: String,
+    pub message: Option<String>,
+    pub code: u16,
+    pub cause: Option<String>,
+}
+
+impl {{error_type}} {
+    fn cause(&self) -> Option<String> {
+        match self {
+            {% for variant in variants %}
+            {{error_type}}::{{variant.detail | to_camel_case}} => None,
+            {% endfor %}
+            {% if db %}
+            {{error_type}}::DbError(cause) => Some(cause.clone()),
+            {% endif %}
+        }
+    }
+}
+
 impl ResponseError for {{error_type}} {
     fn status_code(&self) -> StatusCode {
         match self {
             {% for variant in variants %}
             {{error_type}}::{{variant.detail | to_camel_case}} => StatusCode::{{variant.code_name}},
             {% endfor %}
+            {% if db %}
+            {{error_type}}::DbError(_) => StatusCode::INTERNAL_SERVER_ERROR,
+            {% endif %}
         }
     }
+
+    fn error_response(&self) -> actix_web::HttpResponse {
+        let body = {{error_type}}Body {
+            error: match self {
+                {% for variant in variants %}
+                {{error_type}}::{{variant.detail | to_camel_case}} => "{{variant.detail | to_camel_case}}".to_string(),
+                {% endfor %}
+                {% if db %}
+                {{error_type}}::DbError(_) => "DbError".to_string(),
+                {% endif %}
+            },
+            message: Some(self.to_string()),
+            code: self.status_code().as_u16(),
+            cause: self.cause(),
+        };
+
+        actix_web::HttpResponseBuilder::new(self.status_code())
+            .content_type(actix_web::http::header::ContentType::json())
+            .json(body)
+    }
 }
@@ -0,0 +1,57 @@
+use std::collections::HashMap;
+
+/// Result of a single readiness check.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum Status {
+    Pass,
+    Warn,
+    Fail,
+}
+
+/// Aggregated readiness report returned by `GET /healthcheck`.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Health {
+    pub status: Status,
+    pub checks: HashMap<String, Status>,
+}
+
+impl Health {
+    fn from_checks(checks: HashMap<String, Status>) -> Self {
+        let status = checks
+            .values()
+            .copied()
+            .max_by_key(|status| match status {
+                Status::Pass => 0,
+                Status::Warn => 1,
+                Status::Fail => 2,
+            })
+            .unwrap_or(Status::Pass);
+
+        Self { status, checks }
+    }
+}
+
+/// Generated companion to `ServerState` — probes the dependencies that back
+/// the `/healthcheck` route. Implementors report one `Status` per dependency;
+/// the route aggregates them into the overall `Health` response.
+#[async_trait::async_trait(?Send)]
+pub trait HealthCheck {
+    async fn check(&self) -> HashMap<String, Status>;
+}
+
+async fn healthcheck<S>(state: actix_web::web::Data<S>) -> actix_web::HttpResponse
+where
+    S: HealthCheck,
+{
+    let health = Health::from_checks(state.check().await);
+
+    let status_code = match health.status {
+        Status::Fail => actix_web::http::StatusCode::SERVICE_UNAVAILABLE,
+        Status::Pass | Status::Warn => actix_web::http::StatusCode::OK,
+    };
+
+    actix_web::HttpResponseBuilder::new(status_code)
+        .content_type(actix_web::http::header::ContentType::json())
+        .json(health)
+}
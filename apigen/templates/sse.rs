@@ -0,0 +1,80 @@
+use std::pin::Pin;
+
+/// Server-Sent Event payload emitted by streaming operations.
+#[derive(Debug, Clone, Serialize)]
+pub struct SseEvent<T> {
+    pub data: T,
+    pub event: Option<String>,
+    pub id: Option<String>,
+}
+
+impl<T> SseEvent<T> {
+    pub fn new(data: T) -> Self {
+        Self {
+            data,
+            event: None,
+            id: None,
+        }
+    }
+
+    pub fn event(mut self, event: impl Into<String>) -> Self {
+        self.event = Some(event.into());
+        self
+    }
+
+    pub fn id(mut self, id: impl Into<String>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+}
+
+{% if doc %}
+{% for line in doc.splitlines() %}
+/// {{line}}
+{% endfor %}
+{% endif %}
+/// Generated route handler for the `{{ operation_id }}` SSE operation; this is
+/// what `run_service` registers in the route table, so the connection is held
+/// open for the lifetime of the returned stream.
+async fn {{ operation_id }}_route<A, S>(
+    service: web::Data<A>,
+    state: web::Data<S>,
+    {% for param in params %}
+    {{ param.title }}: web::{{ param.kind }}<{{ param.type }}>,
+    {% endfor %}
+) -> impl actix_web::Responder
+where
+    A: ApiService<S> + 'static,
+    S: ServerState + 'static,
+{
+    let stream = service.{{ operation_id }}(state, {% for param in params %}{{ param.title }}, {% endfor %});
+
+    {{ operation_id }}_into_sse(stream)
+        .customize()
+        .insert_header(("Cache-Control", "no-cache"))
+}
+
+fn {{ operation_id }}_into_sse<T, E>(
+    stream: Pin<Box<dyn futures::Stream<Item = Result<SseEvent<T>, Detailed<E>>>>>,
+) -> actix_web_lab::sse::Sse<impl futures::Stream<Item = Result<actix_web_lab::sse::Event, actix_web::Error>>>
+where
+    T: Serialize,
+    E: ResponseError + 'static,
+{
+    use futures::StreamExt;
+
+    let events = stream.map(|item| {
+        let event = item.map_err(actix_web::Error::from)?;
+        let mut data = actix_web_lab::sse::Data::new_json(&event.data)
+            .map_err(actix_web::error::ErrorInternalServerError)?;
+        if let Some(name) = event.event {
+            data = data.event(name);
+        }
+        if let Some(id) = event.id {
+            data = data.id(id);
+        }
+        Ok(actix_web_lab::sse::Event::Data(data))
+    });
+
+    actix_web_lab::sse::Sse::from_stream(events).with_keep_alive(std::time::Duration::from_secs(15))
+}
@@ -1,12 +1,38 @@
 mod server;
 
-struct State {}
+use deadpool_postgres::{Config, Pool, Runtime};
+use server::api::{DbPool, HealthCheck};
+use std::collections::HashMap;
+
+struct State {
+    pool: Pool,
+}
 
 impl server::ServerState for State {}
 
+impl DbPool for State {
+    fn pool(&self) -> &Pool {
+        &self.pool
+    }
+}
+
+#[async_trait::async_trait(?Send)]
+impl HealthCheck for State {
+    async fn check(&self) -> HashMap<String, server::api::Status> {
+        HashMap::new()
+    }
+}
+
 #[tokio::main]
 async fn main() -> Result<(), std::io::Error> {
-    server::run_server("127.0.0.1:8000", State {}).await?;
+    let mut cfg = Config::new();
+    cfg.host = Some("localhost".to_string());
+    cfg.dbname = Some("helloworld".to_string());
+    let pool = cfg
+        .create_pool(Some(Runtime::Tokio1), tokio_postgres::NoTls)
+        .expect("failed to create db pool");
+
+    server::run_server("127.0.0.1:8000", State { pool }).await?;
 
     Ok(())
 }
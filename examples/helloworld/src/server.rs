@@ -1,8 +1,10 @@
-mod api;
+pub mod api;
 use async_trait::async_trait;
 
 use actix_web::web;
 use api::*;
+use futures::stream;
+use std::pin::Pin;
 
 #[async_trait(?Send)]
 pub trait ServerState {}
@@ -12,19 +14,31 @@ struct DefaultServer;
 #[async_trait(?Send)]
 impl<S> ApiService<S> for DefaultServer
 where
-    S: ServerState + Send + Sync + 'static,
+    S: ServerState + DbPool + Send + Sync + 'static,
 {
     async fn hello_user(
-        _data: web::Data<S>,
+        &self,
+        state: web::Data<S>,
         path: web::Path<HelloUserPath>,
     ) -> Result<String, Detailed<HelloUserError>> {
+        let _client = get_client(&state).await.map_err(Detailed::from)?;
+
         Ok(format!("Hello, {}", path.user))
     }
+
+    fn watch_status(
+        &self,
+        _state: web::Data<S>,
+    ) -> Pin<Box<dyn futures::Stream<Item = Result<SseEvent<StatusUpdate>, Detailed<WatchStatusError>>>>> {
+        Box::pin(stream::once(async {
+            Ok(SseEvent::new(StatusUpdate { ready: true }).event("status"))
+        }))
+    }
 }
 
 pub async fn run_server<S>(bind: &str, initial_state: S) -> Result<(), std::io::Error>
 where
-    S: ServerState + Send + Sync + 'static,
+    S: ServerState + DbPool + Send + Sync + 'static,
 {
-    api::run_service::<DefaultServer, S>(bind, initial_state).await
+    api::run_service(DefaultServer, bind, initial_state).await
 }